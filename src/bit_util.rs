@@ -1,19 +1,26 @@
+//! Allocation-free bit-twiddling helpers, safe to use from `#![no_std]` code.
+//!
+//! This module doesn't need any changes to be `no_std`-safe. It's now backed by the crate-root
+//! `no_std` wiring (`std` feature in `Cargo.toml`, `#![cfg_attr(not(feature = "std"), no_std)]` on
+//! the crate root). Except for `extract_channel_from_status_byte`, these functions delegate to
+//! the internal helpers in `util.rs` that do the actual bit twiddling.
+
 use crate::{Channel, U14, U7};
 
 pub fn extract_high_7_bit_value_from_14_bit_value(value: U14) -> U7 {
-    U7(((value.get() >> 7) & 0x7f) as u8)
+    U7(crate::util::extract_high_7_bit_value_from_14_bit_value(value))
 }
 
 pub fn extract_low_7_bit_value_from_14_bit_value(value: U14) -> U7 {
-    U7((value.get() & 0x7f) as u8)
+    U7(crate::util::extract_low_7_bit_value_from_14_bit_value(value))
 }
 
 pub fn build_14_bit_value_from_two_7_bit_values(high: U7, low: U7) -> U14 {
-    U14((u16::from(high) << 7) | u16::from(low))
+    crate::util::build_14_bit_value_from_two_7_bit_values(high.get(), low.get())
 }
 
 pub fn build_status_byte(type_byte: u8, channel: Channel) -> u8 {
-    type_byte | channel.get()
+    crate::util::with_low_nibble_added(type_byte, channel)
 }
 
 pub fn extract_channel_from_status_byte(byte: u8) -> Channel {
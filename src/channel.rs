@@ -0,0 +1,46 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A MIDI channel (0-15).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Channel(pub(crate) u8);
+
+impl Channel {
+    /// The smallest valid channel, 0.
+    pub const MIN: Channel = Channel(0);
+    /// The largest valid channel, 15.
+    pub const MAX: Channel = Channel(15);
+    /// The number of channels in total, 16.
+    pub const COUNT: usize = 16;
+
+    /// Creates a channel.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given number is greater than 15.
+    pub fn new(number: u8) -> Channel {
+        assert!(number <= 15);
+        Channel(number)
+    }
+
+    /// Creates a channel without checking the given number.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that the given number is less than or equal to 15.
+    pub const unsafe fn new_unchecked(number: u8) -> Channel {
+        Channel(number)
+    }
+
+    /// Returns the number of this channel.
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<Channel> for u8 {
+    fn from(value: Channel) -> Self {
+        value.0
+    }
+}
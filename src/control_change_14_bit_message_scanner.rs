@@ -0,0 +1,156 @@
+use crate::{ControlChange14BitMessage, ControllerNumber, ShortMessage, ShortMessageType, U14};
+
+const CONTROLLER_COUNT: usize = 32;
+const CHANNEL_COUNT: usize = 16;
+
+/// Scans a stream of [`ShortMessage`]s for [`ControlChange14BitMessage`]s.
+///
+/// Real-world MIDI streams are messier than the textbook "MSB immediately followed by LSB"
+/// sequence: some devices send the LSB before the MSB, and fine/coarse knobs often send one MSB
+/// followed by a whole stream of LSB-only updates. An incoming LSB always combines with whatever
+/// MSB was last seen (or `0` if none has been seen yet) and emits immediately, so out-of-order
+/// halves and LSB-only streams both just work.
+///
+/// An MSB on its own is a different story: since it never carries fresh information about the
+/// other half, emitting on every bare MSB would mean repeating the same 14-bit value whenever a
+/// device resends its MSB as a kind of keep-alive. By default [`feed`] therefore only emits a
+/// message when an LSB arrives; [`set_emit_on_repeated_msb`] can be used to opt into also emitting
+/// on a bare MSB.
+///
+/// Both halves are masked with `0x7f` before being combined, so a malformed data byte can't bleed
+/// into the other half of the 14-bit value.
+///
+/// [`ShortMessage`]: trait.ShortMessage.html
+/// [`ControlChange14BitMessage`]: struct.ControlChange14BitMessage.html
+/// [`feed`]: #method.feed
+/// [`set_emit_on_repeated_msb`]: #method.set_emit_on_repeated_msb
+#[derive(Clone, Debug)]
+pub struct ControlChange14BitMessageScanner {
+    emit_on_repeated_msb: bool,
+    states: [[ScanState; CONTROLLER_COUNT]; CHANNEL_COUNT],
+}
+
+impl Default for ControlChange14BitMessageScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ControlChange14BitMessageScanner {
+    /// Creates a scanner that doesn't emit a message for a bare MSB, only for an LSB.
+    pub fn new() -> ControlChange14BitMessageScanner {
+        ControlChange14BitMessageScanner {
+            emit_on_repeated_msb: false,
+            states: [[ScanState::default(); CONTROLLER_COUNT]; CHANNEL_COUNT],
+        }
+    }
+
+    /// Sets whether a bare, repeated MSB (one with no LSB of its own) should still emit a
+    /// message, using whatever LSB was last seen.
+    ///
+    /// This is off by default because many controllers repeat the MSB as a kind of keep-alive,
+    /// which would otherwise cause the same 14-bit value to be reported over and over again.
+    pub fn set_emit_on_repeated_msb(&mut self, emit: bool) {
+        self.emit_on_repeated_msb = emit;
+    }
+
+    /// Feeds the scanner a short message, returning a complete 14-bit Control Change message if
+    /// this message was one half of such a message.
+    pub fn feed(&mut self, msg: &impl ShortMessage) -> Option<ControlChange14BitMessage> {
+        if msg.r#type() != ShortMessageType::ControlChange {
+            return None;
+        }
+        let channel = msg.channel()?;
+        let controller_number = msg.controller_number()?;
+        let value = msg.control_value()?.get();
+        let cn = usize::from(controller_number.get());
+        let channel_states = &mut self.states[usize::from(channel.get())];
+        if cn < CONTROLLER_COUNT {
+            let state = &mut channel_states[cn];
+            state.msb = value;
+            if !self.emit_on_repeated_msb {
+                return None;
+            }
+            Some(ControlChange14BitMessage::new(
+                channel,
+                controller_number,
+                combine_14_bit_value(state.msb, state.lsb),
+            ))
+        } else if cn < 2 * CONTROLLER_COUNT {
+            let primary_cn = cn - CONTROLLER_COUNT;
+            let state = &mut channel_states[primary_cn];
+            state.lsb = value;
+            let msb_controller_number = unsafe { ControllerNumber::new_unchecked(primary_cn as u8) };
+            Some(ControlChange14BitMessage::new(
+                channel,
+                msb_controller_number,
+                combine_14_bit_value(state.msb, state.lsb),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct ScanState {
+    msb: u8,
+    lsb: u8,
+}
+
+fn combine_14_bit_value(high: u8, low: u8) -> U14 {
+    unsafe { U14::new_unchecked((u16::from(high & 0x7f) << 7) | u16::from(low & 0x7f)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{channel as ch, controller_number as cn, u14, u7};
+    use crate::{RawShortMessage, ShortMessageFactory};
+
+    #[test]
+    fn msb_then_lsb() {
+        let mut scanner = ControlChange14BitMessageScanner::new();
+        assert!(scanner
+            .feed(&RawShortMessage::control_change(ch(0), cn(7), u7(8)))
+            .is_none());
+        let msg = scanner
+            .feed(&RawShortMessage::control_change(ch(0), cn(39), u7(33)))
+            .unwrap();
+        assert_eq!(msg.value(), u14(1057));
+    }
+
+    #[test]
+    fn lsb_before_msb() {
+        let mut scanner = ControlChange14BitMessageScanner::new();
+        let msg = scanner
+            .feed(&RawShortMessage::control_change(ch(0), cn(39), u7(33)))
+            .unwrap();
+        assert_eq!(msg.msb_controller_number(), cn(7));
+        assert_eq!(msg.value(), u14(33));
+    }
+
+    #[test]
+    fn bare_msb_is_suppressed_by_default() {
+        let mut scanner = ControlChange14BitMessageScanner::new();
+        assert!(scanner
+            .feed(&RawShortMessage::control_change(ch(0), cn(7), u7(8)))
+            .is_none());
+        scanner.feed(&RawShortMessage::control_change(ch(0), cn(39), u7(33)));
+        // A later bare MSB still isn't paired with a fresh LSB of its own, so it's suppressed too.
+        assert!(scanner
+            .feed(&RawShortMessage::control_change(ch(0), cn(7), u7(9)))
+            .is_none());
+    }
+
+    #[test]
+    fn bare_msb_can_be_enabled() {
+        let mut scanner = ControlChange14BitMessageScanner::new();
+        scanner.set_emit_on_repeated_msb(true);
+        scanner.feed(&RawShortMessage::control_change(ch(0), cn(7), u7(8)));
+        let msg = scanner
+            .feed(&RawShortMessage::control_change(ch(0), cn(7), u7(9)))
+            .unwrap();
+        assert_eq!(msg.value().get(), 9 << 7);
+    }
+}
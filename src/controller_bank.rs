@@ -0,0 +1,196 @@
+use crate::{Channel, ControllerNumber, ShortMessage, ShortMessageFactory, ShortMessageType, U14, U7};
+
+const CONTROLLER_COUNT: usize = 32;
+const CHANNEL_COUNT: usize = 16;
+
+/// Keeps track of the current value of controllers 0-31 on every channel.
+///
+/// Controllers 32-63 are folded into their partner in the range 0-31 as its LSB half.
+/// Controllers 64-127 are not tracked.
+///
+/// Feeding a stream of [`ShortMessage`]s into [`feed`] builds up a live snapshot that can be
+/// queried at any time via [`controller_value`], which is handy when building the state model
+/// behind a MIDI input port. 14-bit Control Change pairs (a controller number in the range 0-31,
+/// whose low byte arrives on the corresponding controller number in the range 32-63) are
+/// automatically merged into a single [`U14`] value as soon as the LSB half has been seen at
+/// least once. Until then, the value reported is just the plain 7-bit value sent so far, which
+/// keeps this useful even for devices that never send the LSB half.
+///
+/// [`ShortMessage`]: trait.ShortMessage.html
+/// [`U14`]: struct.U14.html
+/// [`feed`]: #method.feed
+/// [`controller_value`]: #method.controller_value
+#[derive(Clone, Debug)]
+pub struct ControllerBank {
+    channels: [ChannelState; CHANNEL_COUNT],
+}
+
+impl Default for ControllerBank {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ControllerBank {
+    /// Creates a controller bank in which every controller is assumed to be 0 and 7-bit.
+    pub fn new() -> ControllerBank {
+        ControllerBank {
+            channels: [ChannelState::default(); CHANNEL_COUNT],
+        }
+    }
+
+    /// Updates this bank with the given short message.
+    ///
+    /// Messages other than Control Change are ignored.
+    pub fn feed(&mut self, msg: &impl ShortMessage) {
+        if msg.r#type() != ShortMessageType::ControlChange {
+            return;
+        }
+        let channel = match msg.channel() {
+            Some(c) => c,
+            None => return,
+        };
+        let controller_number = match msg.controller_number() {
+            Some(cn) => cn,
+            None => return,
+        };
+        let value = match msg.control_value() {
+            Some(v) => v,
+            None => return,
+        };
+        self.channels[usize::from(channel.get())].process(controller_number, value);
+    }
+
+    /// Returns the current value of the given controller on the given channel.
+    ///
+    /// For a controller number in the 14-bit range (0-31) whose LSB partner has already been
+    /// seen, this is a genuine 14-bit value. For any other controller number in the range 0-31
+    /// it's just the plain 7-bit value, widened to [`U14`]. For controller numbers 32-127 it's
+    /// always 0.
+    ///
+    /// [`U14`]: struct.U14.html
+    pub fn controller_value(&self, channel: Channel, controller_number: ControllerNumber) -> U14 {
+        self.channels[usize::from(channel.get())].value(controller_number)
+    }
+
+    /// Returns whether the given controller on the given channel has been recognized as a 14-bit
+    /// controller, i.e. whether its LSB partner has been seen at least once.
+    pub fn is_14_bit(&self, channel: Channel, controller_number: ControllerNumber) -> bool {
+        self.channels[usize::from(channel.get())].is_14_bit(controller_number)
+    }
+
+    /// Resets all controller values of all channels to 0.
+    ///
+    /// If `notes_off` is `true`, also returns an All Notes Off message for each of the 16
+    /// channels, which the caller can send out in order to silence any notes that might still be
+    /// hanging.
+    pub fn reset<T: ShortMessageFactory>(&mut self, notes_off: bool) -> Option<[T; CHANNEL_COUNT]> {
+        self.channels = [ChannelState::default(); CHANNEL_COUNT];
+        if !notes_off {
+            return None;
+        }
+        Some(core::array::from_fn(|i| {
+            T::control_change(
+                unsafe { Channel::new_unchecked(i as u8) },
+                crate::controller_numbers::ALL_NOTES_OFF,
+                U7::MIN,
+            )
+        }))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct ChannelState {
+    controller_val: [u16; CONTROLLER_COUNT],
+    is_14_bit: [bool; CONTROLLER_COUNT],
+    msb: [u8; CONTROLLER_COUNT],
+    lsb: [u8; CONTROLLER_COUNT],
+}
+
+impl Default for ChannelState {
+    fn default() -> Self {
+        ChannelState {
+            controller_val: [0; CONTROLLER_COUNT],
+            is_14_bit: [false; CONTROLLER_COUNT],
+            msb: [0; CONTROLLER_COUNT],
+            lsb: [0; CONTROLLER_COUNT],
+        }
+    }
+}
+
+impl ChannelState {
+    fn process(&mut self, controller_number: ControllerNumber, value: U7) {
+        let cn = usize::from(controller_number.get());
+        let raw = value.get();
+        if cn < CONTROLLER_COUNT {
+            // MSB (or plain 7-bit) slot of a possible 14-bit pair.
+            self.msb[cn] = raw;
+            let old_cv = self.controller_val[cn];
+            self.controller_val[cn] = if self.is_14_bit[cn] {
+                ((raw as u16 & 0x7f) << 7) | (old_cv & 0x7f)
+            } else {
+                raw as u16
+            };
+        } else if cn < 2 * CONTROLLER_COUNT {
+            // LSB slot of a 14-bit pair. Recombine from the scratch MSB byte, not from
+            // `controller_val`, which may still hold an unshifted 7-bit passthrough value if this
+            // is the first LSB received after one or more MSB-only messages.
+            let primary = cn - CONTROLLER_COUNT;
+            self.lsb[primary] = raw;
+            self.is_14_bit[primary] = true;
+            self.controller_val[primary] =
+                (u16::from(self.msb[primary] & 0x7f) << 7) | (raw as u16 & 0x7f);
+        }
+        // Controllers 64 and above don't take part in the 14-bit scheme, nothing to track.
+    }
+
+    fn value(&self, controller_number: ControllerNumber) -> U14 {
+        let cn = usize::from(controller_number.get());
+        if cn >= CONTROLLER_COUNT {
+            return U14::MIN;
+        }
+        unsafe { U14::new_unchecked(self.controller_val[cn]) }
+    }
+
+    fn is_14_bit(&self, controller_number: ControllerNumber) -> bool {
+        let cn = usize::from(controller_number.get());
+        cn < CONTROLLER_COUNT && self.is_14_bit[cn]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{channel as ch, controller_number as cn};
+    use crate::RawShortMessage;
+
+    #[test]
+    fn merges_msb_then_lsb() {
+        let mut bank = ControllerBank::new();
+        bank.feed(&RawShortMessage::control_change(ch(0), cn(7), U7::new(8)));
+        assert_eq!(bank.controller_value(ch(0), cn(7)).get(), 8);
+        assert!(!bank.is_14_bit(ch(0), cn(7)));
+        bank.feed(&RawShortMessage::control_change(ch(0), cn(39), U7::new(33)));
+        assert!(bank.is_14_bit(ch(0), cn(7)));
+        assert_eq!(bank.controller_value(ch(0), cn(7)).get(), 1057);
+    }
+
+    #[test]
+    fn stray_high_bit_does_not_corrupt_other_half() {
+        let mut bank = ControllerBank::new();
+        // 0xff has a stray bit above the 7 data bits.
+        let stray = unsafe { U7::new_unchecked(0xff) };
+        bank.feed(&RawShortMessage::control_change(ch(0), cn(7), stray));
+        bank.feed(&RawShortMessage::control_change(ch(0), cn(39), U7::new(127)));
+        assert_eq!(bank.controller_value(ch(0), cn(7)).get(), 0x3fff);
+    }
+
+    #[test]
+    fn reset_clears_values() {
+        let mut bank = ControllerBank::new();
+        bank.feed(&RawShortMessage::control_change(ch(0), cn(7), U7::new(100)));
+        let notes_off: Option<[RawShortMessage; 16]> = bank.reset(false);
+        assert!(notes_off.is_none());
+        assert_eq!(bank.controller_value(ch(0), cn(7)).get(), 0);
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A MIDI controller number (0-127), as carried by the first data byte of a Control Change
+/// message.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ControllerNumber(pub(crate) u8);
+
+impl ControllerNumber {
+    /// The smallest valid controller number, 0.
+    pub const MIN: ControllerNumber = ControllerNumber(0);
+    /// The largest valid controller number, 127.
+    pub const MAX: ControllerNumber = ControllerNumber(127);
+
+    /// Creates a controller number.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given number is greater than 127.
+    pub fn new(number: u8) -> ControllerNumber {
+        assert!(number <= 127);
+        ControllerNumber(number)
+    }
+
+    /// Creates a controller number without checking the given number.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that the given number is less than or equal to 127.
+    pub const unsafe fn new_unchecked(number: u8) -> ControllerNumber {
+        ControllerNumber(number)
+    }
+
+    /// Returns the number of this controller number.
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+
+    /// If this controller number can serve as the MSB half of a 14-bit Control Change message
+    /// (i.e. it's in the range 0-31), returns the controller number that carries the
+    /// corresponding LSB (which is this number plus 32).
+    pub fn corresponding_14_bit_lsb_controller_number(&self) -> Option<ControllerNumber> {
+        if self.0 < 32 {
+            Some(ControllerNumber(self.0 + 32))
+        } else {
+            None
+        }
+    }
+}
+
+impl From<ControllerNumber> for u8 {
+    fn from(value: ControllerNumber) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,29 @@
+//! Well-known controller numbers used by name elsewhere in this crate and by callers who'd rather
+//! not memorize raw numbers.
+
+use crate::ControllerNumber;
+
+/// Controller number for the coarse (MSB) half of Channel Volume.
+pub const CHANNEL_VOLUME: ControllerNumber = unsafe { ControllerNumber::new_unchecked(7) };
+/// Controller number for the fine (LSB) half of Channel Volume.
+pub const CHANNEL_VOLUME_LSB: ControllerNumber = unsafe { ControllerNumber::new_unchecked(39) };
+
+/// Controller number for All Notes Off.
+pub const ALL_NOTES_OFF: ControllerNumber = unsafe { ControllerNumber::new_unchecked(123) };
+
+/// Controller number for the MSB half of selecting a Registered Parameter Number.
+pub const REGISTERED_PARAMETER_NUMBER_MSB: ControllerNumber =
+    unsafe { ControllerNumber::new_unchecked(101) };
+/// Controller number for the LSB half of selecting a Registered Parameter Number.
+pub const REGISTERED_PARAMETER_NUMBER_LSB: ControllerNumber =
+    unsafe { ControllerNumber::new_unchecked(100) };
+/// Controller number for the MSB half of selecting a Non-Registered Parameter Number.
+pub const NON_REGISTERED_PARAMETER_NUMBER_MSB: ControllerNumber =
+    unsafe { ControllerNumber::new_unchecked(99) };
+/// Controller number for the LSB half of selecting a Non-Registered Parameter Number.
+pub const NON_REGISTERED_PARAMETER_NUMBER_LSB: ControllerNumber =
+    unsafe { ControllerNumber::new_unchecked(98) };
+/// Controller number for the MSB half of an (N)RPN data entry value.
+pub const DATA_ENTRY_MSB: ControllerNumber = unsafe { ControllerNumber::new_unchecked(6) };
+/// Controller number for the LSB half of an (N)RPN data entry value.
+pub const DATA_ENTRY_LSB: ControllerNumber = unsafe { ControllerNumber::new_unchecked(38) };
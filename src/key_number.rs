@@ -0,0 +1,50 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A MIDI key number (0-127), as carried by the first data byte of a Note On or Note Off message.
+///
+/// MIDI note number 60 is "middle C". See [`format_note_name`] and [`parse_note_name`] for
+/// converting to and from human-readable note names.
+///
+/// [`format_note_name`]: #method.format_note_name
+/// [`parse_note_name`]: #method.parse_note_name
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyNumber(pub(crate) u8);
+
+impl KeyNumber {
+    /// The smallest valid key number, 0.
+    pub const MIN: KeyNumber = KeyNumber(0);
+    /// The largest valid key number, 127.
+    pub const MAX: KeyNumber = KeyNumber(127);
+
+    /// Creates a key number.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given number is greater than 127.
+    pub fn new(number: u8) -> KeyNumber {
+        assert!(number <= 127);
+        KeyNumber(number)
+    }
+
+    /// Creates a key number without checking the given number.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that the given number is less than or equal to 127.
+    pub const unsafe fn new_unchecked(number: u8) -> KeyNumber {
+        KeyNumber(number)
+    }
+
+    /// Returns the number of this key number.
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<KeyNumber> for u8 {
+    fn from(value: KeyNumber) -> Self {
+        value.0
+    }
+}
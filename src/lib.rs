@@ -0,0 +1,38 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Basic but solid data structures and conversion logic for dealing with MIDI messages.
+
+mod bit_util;
+mod channel;
+mod control_change_14_bit_message;
+mod control_change_14_bit_message_scanner;
+mod controller_bank;
+mod controller_number;
+pub mod controller_numbers;
+mod key_number;
+mod note_name;
+mod parameter_number_message;
+mod parameter_number_message_scanner;
+mod short_message;
+pub mod test_util;
+mod u14;
+mod u7;
+mod util;
+
+pub use bit_util::*;
+pub use channel::*;
+pub use control_change_14_bit_message::*;
+pub use control_change_14_bit_message_scanner::*;
+pub use controller_bank::*;
+pub use controller_number::*;
+pub use key_number::*;
+pub use note_name::*;
+pub use parameter_number_message::*;
+pub use parameter_number_message_scanner::*;
+pub use short_message::*;
+pub use u14::*;
+pub use u7::*;
+
+/// A 7-bit value represented as a raw byte, used internally where carrying the full [`U7`]
+/// newtype would be more ceremony than the call site needs.
+pub type SevenBitValue = u8;
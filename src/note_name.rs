@@ -0,0 +1,211 @@
+use crate::KeyNumber;
+use core::fmt;
+
+const SHARP_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+const FLAT_NAMES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+];
+
+/// Identifies which octave MIDI note number 60 ("middle C") is considered to belong to.
+///
+/// There's a well-known ambiguity here: depending on the software, note 60 is labeled "C3", "C4"
+/// or "C5". Rather than hard-coding one of these conventions, [`KeyNumber::format_note_name`] and
+/// [`KeyNumber::parse_note_name`] take a `MiddleCOctave` so callers can match whatever convention
+/// their users expect.
+///
+/// [`KeyNumber::format_note_name`]: struct.KeyNumber.html#method.format_note_name
+/// [`KeyNumber::parse_note_name`]: struct.KeyNumber.html#method.parse_note_name
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct MiddleCOctave(i8);
+
+impl MiddleCOctave {
+    /// The most widely used convention, in which MIDI note 60 is "C4". This is the default.
+    pub const MIDI_STANDARD: MiddleCOctave = MiddleCOctave(4);
+
+    /// Creates a convention in which MIDI note 60 is "C`octave`".
+    pub const fn new(octave: i8) -> MiddleCOctave {
+        MiddleCOctave(octave)
+    }
+
+    /// Returns the octave number that MIDI note 60 is labeled with, under this convention.
+    pub const fn get(&self) -> i8 {
+        self.0
+    }
+}
+
+impl Default for MiddleCOctave {
+    fn default() -> MiddleCOctave {
+        MiddleCOctave::MIDI_STANDARD
+    }
+}
+
+/// A human-readable rendering of a [`KeyNumber`], e.g. "C#4" or "Bb-1".
+///
+/// Implements [`Display`] rather than eagerly building an owned string, so it works the same way
+/// whether or not an allocator is available. Create one via [`KeyNumber::format_note_name`].
+///
+/// [`KeyNumber`]: struct.KeyNumber.html
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`KeyNumber::format_note_name`]: struct.KeyNumber.html#method.format_note_name
+#[derive(Copy, Clone, Debug)]
+pub struct NoteName {
+    key_number: KeyNumber,
+    middle_c_octave: MiddleCOctave,
+    use_flats: bool,
+}
+
+impl fmt::Display for NoteName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (pitch_class, octave) = split_key_number(self.key_number, self.middle_c_octave);
+        let name = if self.use_flats {
+            FLAT_NAMES[pitch_class]
+        } else {
+            SHARP_NAMES[pitch_class]
+        };
+        write!(f, "{}{}", name, octave)
+    }
+}
+
+impl KeyNumber {
+    /// Returns a displayable note name for this key number, e.g. "C#4" or, with `use_flats` set,
+    /// "Db4".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use helgoboss_midi::{KeyNumber, MiddleCOctave};
+    ///
+    /// let key_number = KeyNumber::new(60);
+    /// assert_eq!(
+    ///     key_number
+    ///         .format_note_name(MiddleCOctave::MIDI_STANDARD, false)
+    ///         .to_string(),
+    ///     "C4"
+    /// );
+    /// ```
+    pub fn format_note_name(&self, middle_c_octave: MiddleCOctave, use_flats: bool) -> NoteName {
+        NoteName {
+            key_number: *self,
+            middle_c_octave,
+            use_flats,
+        }
+    }
+
+    /// Parses a note name such as "C#4" or "Bb-1" into a key number, using the given middle-C
+    /// octave convention.
+    ///
+    /// Accepts both sharps (`#`) and flats (`b`/`B` after the letter), upper or lower case letter
+    /// names, and negative octave numbers. Returns `None` if the name isn't a valid note name or
+    /// if it resolves to a MIDI note number outside the representable range.
+    pub fn parse_note_name(name: &str, middle_c_octave: MiddleCOctave) -> Option<KeyNumber> {
+        let mut chars = name.chars();
+        let letter = chars.next()?;
+        let base_pitch_class: i32 = match letter.to_ascii_uppercase() {
+            'C' => 0,
+            'D' => 2,
+            'E' => 4,
+            'F' => 5,
+            'G' => 7,
+            'A' => 9,
+            'B' => 11,
+            _ => return None,
+        };
+        let rest = chars.as_str();
+        let (accidental, rest): (i32, &str) = match rest.chars().next() {
+            Some('#') | Some('s') | Some('S') => (1, &rest[1..]),
+            Some('b') | Some('B') => (-1, &rest[1..]),
+            _ => (0, rest),
+        };
+        let octave: i32 = rest.parse().ok()?;
+        let pitch_class = base_pitch_class + accidental;
+        let note_number = 60 + (octave - i32::from(middle_c_octave.get())) * 12 + pitch_class;
+        if !(0..=127).contains(&note_number) {
+            return None;
+        }
+        Some(KeyNumber::new(note_number as u8))
+    }
+}
+
+fn split_key_number(key_number: KeyNumber, middle_c_octave: MiddleCOctave) -> (usize, i32) {
+    let note_number = i32::from(key_number.get());
+    let pitch_class = (note_number % 12) as usize;
+    let octave =
+        i32::from(middle_c_octave.get()) + (note_number - pitch_class as i32) / 12 - 5;
+    (pitch_class, octave)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::key_number as kn;
+
+    #[test]
+    fn formats_middle_c() {
+        let name = kn(60).format_note_name(MiddleCOctave::MIDI_STANDARD, false);
+        assert_eq!(name.to_string(), "C4");
+    }
+
+    #[test]
+    fn formats_sharp_and_flat() {
+        let key_number = kn(61);
+        assert_eq!(
+            key_number
+                .format_note_name(MiddleCOctave::MIDI_STANDARD, false)
+                .to_string(),
+            "C#4"
+        );
+        assert_eq!(
+            key_number
+                .format_note_name(MiddleCOctave::MIDI_STANDARD, true)
+                .to_string(),
+            "Db4"
+        );
+    }
+
+    #[test]
+    fn formats_negative_octave() {
+        let name = kn(10).format_note_name(MiddleCOctave::MIDI_STANDARD, true);
+        assert_eq!(name.to_string(), "Bb-1");
+    }
+
+    #[test]
+    fn parses_sharp_and_flat() {
+        assert_eq!(
+            KeyNumber::parse_note_name("C#4", MiddleCOctave::MIDI_STANDARD),
+            Some(kn(61))
+        );
+        assert_eq!(
+            KeyNumber::parse_note_name("Db4", MiddleCOctave::MIDI_STANDARD),
+            Some(kn(61))
+        );
+    }
+
+    #[test]
+    fn parses_negative_octave() {
+        assert_eq!(
+            KeyNumber::parse_note_name("Bb-1", MiddleCOctave::MIDI_STANDARD),
+            Some(kn(10))
+        );
+    }
+
+    #[test]
+    fn parses_respects_custom_middle_c_octave() {
+        // Under a convention where middle C is "C3", note 60 parses from "C3" instead of "C4".
+        let custom = MiddleCOctave::new(3);
+        assert_eq!(KeyNumber::parse_note_name("C3", custom), Some(kn(60)));
+    }
+
+    #[test]
+    fn rejects_out_of_range_and_garbage() {
+        assert_eq!(
+            KeyNumber::parse_note_name("C10", MiddleCOctave::MIDI_STANDARD),
+            None
+        );
+        assert_eq!(
+            KeyNumber::parse_note_name("H4", MiddleCOctave::MIDI_STANDARD),
+            None
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    extract_high_7_bit_value_from_14_bit_value, extract_low_7_bit_value_from_14_bit_value, Channel,
+    ShortMessageFactory, U14, U7,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The parameter number that, when selected, means "no parameter is selected" and therefore
+/// terminates an (N)RPN data entry sequence.
+///
+/// [`ParameterNumberMessageScanner`] treats this as a signal to clear its currently selected
+/// parameter number so that subsequent data entry messages aren't misattributed to the previously
+/// selected parameter.
+///
+/// [`ParameterNumberMessageScanner`]: struct.ParameterNumberMessageScanner.html
+pub const NULL_PARAMETER_NUMBER: U14 = unsafe { U14::new_unchecked(0x3fff) };
+
+/// An (N)RPN message, that is, a 7-bit or 14-bit Registered or Non-Registered Parameter Number
+/// message.
+///
+/// On the wire this is represented as a short run of Control Change messages on one channel:
+/// 2 messages to select the parameter number (CC 101/100 for RPN, CC 99/98 for NRPN) followed by
+/// 1 or 2 messages to transmit the value (CC 6, optionally followed by CC 38 for 14-bit
+/// precision). [`ParameterNumberMessageScanner`] reassembles this message from such a run, and
+/// [`to_short_messages`] produces it again.
+///
+/// # Example
+///
+/// ```
+/// use helgoboss_midi::{Channel, ParameterNumberMessage, RawShortMessage, U14, U7};
+///
+/// let msg = ParameterNumberMessage::registered_14_bit(
+///     Channel::new(0),
+///     U14::new(420),
+///     U14::new(1057),
+/// );
+/// assert!(msg.is_registered());
+/// assert!(msg.is_14_bit());
+/// let short_messages: [Option<RawShortMessage>; 4] = msg.to_short_messages();
+/// assert!(short_messages.iter().all(Option::is_some));
+/// ```
+///
+/// [`ParameterNumberMessageScanner`]: struct.ParameterNumberMessageScanner.html
+/// [`to_short_messages`]: #method.to_short_messages
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParameterNumberMessage {
+    channel: Channel,
+    number: U14,
+    value: U14,
+    is_14_bit: bool,
+    is_registered: bool,
+}
+
+impl ParameterNumberMessage {
+    /// Creates a non-registered 7-bit parameter number message.
+    pub fn non_registered_7_bit(
+        channel: Channel,
+        number: U14,
+        value: U7,
+    ) -> ParameterNumberMessage {
+        ParameterNumberMessage::new_internal(
+            channel,
+            number,
+            U14::new(u16::from(value)),
+            false,
+            false,
+        )
+    }
+
+    /// Creates a non-registered 14-bit parameter number message.
+    pub fn non_registered_14_bit(
+        channel: Channel,
+        number: U14,
+        value: U14,
+    ) -> ParameterNumberMessage {
+        ParameterNumberMessage::new_internal(channel, number, value, true, false)
+    }
+
+    /// Creates a registered 7-bit parameter number message.
+    pub fn registered_7_bit(channel: Channel, number: U14, value: U7) -> ParameterNumberMessage {
+        ParameterNumberMessage::new_internal(
+            channel,
+            number,
+            U14::new(u16::from(value)),
+            false,
+            true,
+        )
+    }
+
+    /// Creates a registered 14-bit parameter number message.
+    pub fn registered_14_bit(channel: Channel, number: U14, value: U14) -> ParameterNumberMessage {
+        ParameterNumberMessage::new_internal(channel, number, value, true, true)
+    }
+
+    pub(crate) fn new_internal(
+        channel: Channel,
+        number: U14,
+        value: U14,
+        is_14_bit: bool,
+        is_registered: bool,
+    ) -> ParameterNumberMessage {
+        ParameterNumberMessage {
+            channel,
+            number,
+            value,
+            is_14_bit,
+            is_registered,
+        }
+    }
+
+    /// Returns the channel of this message.
+    pub fn channel(&self) -> Channel {
+        self.channel
+    }
+
+    /// Returns the parameter number of this message.
+    pub fn number(&self) -> U14 {
+        self.number
+    }
+
+    /// Returns the value of this message.
+    ///
+    /// If [`is_14_bit`] is `false`, only the lower 7 bits are meaningful.
+    ///
+    /// [`is_14_bit`]: #method.is_14_bit
+    pub fn value(&self) -> U14 {
+        self.value
+    }
+
+    /// Returns whether this message carries a 14-bit value (as opposed to a 7-bit value).
+    pub fn is_14_bit(&self) -> bool {
+        self.is_14_bit
+    }
+
+    /// Returns whether this message is a Registered Parameter Number message (as opposed to a
+    /// Non-Registered one).
+    pub fn is_registered(&self) -> bool {
+        self.is_registered
+    }
+
+    /// Translates this message into a run of short messages, which need to be sent in a row in
+    /// order to encode this (N)RPN message.
+    ///
+    /// The last slot is `None` if this message is 7-bit, because then there's no data entry LSB
+    /// to send and the 7-bit value itself goes out as the data entry MSB.
+    pub fn to_short_messages<T: ShortMessageFactory>(&self) -> [Option<T>; 4] {
+        let (number_selector_msb, number_selector_lsb) = if self.is_registered {
+            (
+                crate::controller_numbers::REGISTERED_PARAMETER_NUMBER_MSB,
+                crate::controller_numbers::REGISTERED_PARAMETER_NUMBER_LSB,
+            )
+        } else {
+            (
+                crate::controller_numbers::NON_REGISTERED_PARAMETER_NUMBER_MSB,
+                crate::controller_numbers::NON_REGISTERED_PARAMETER_NUMBER_LSB,
+            )
+        };
+        [
+            Some(T::control_change(
+                self.channel,
+                number_selector_msb,
+                extract_high_7_bit_value_from_14_bit_value(self.number),
+            )),
+            Some(T::control_change(
+                self.channel,
+                number_selector_lsb,
+                extract_low_7_bit_value_from_14_bit_value(self.number),
+            )),
+            Some(T::control_change(
+                self.channel,
+                crate::controller_numbers::DATA_ENTRY_MSB,
+                if self.is_14_bit {
+                    extract_high_7_bit_value_from_14_bit_value(self.value)
+                } else {
+                    extract_low_7_bit_value_from_14_bit_value(self.value)
+                },
+            )),
+            if self.is_14_bit {
+                Some(T::control_change(
+                    self.channel,
+                    crate::controller_numbers::DATA_ENTRY_LSB,
+                    extract_low_7_bit_value_from_14_bit_value(self.value),
+                ))
+            } else {
+                None
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{channel as ch, u14, u7};
+    use crate::RawShortMessage;
+
+    #[test]
+    fn registered_14_bit_round_trip() {
+        let msg = ParameterNumberMessage::registered_14_bit(ch(0), u14(420), u14(1057));
+        assert_eq!(msg.channel(), ch(0));
+        assert_eq!(msg.number(), u14(420));
+        assert_eq!(msg.value(), u14(1057));
+        assert!(msg.is_registered());
+        assert!(msg.is_14_bit());
+        let short_msgs: [Option<RawShortMessage>; 4] = msg.to_short_messages();
+        assert!(short_msgs[3].is_some());
+    }
+
+    #[test]
+    fn non_registered_7_bit_has_no_lsb_message() {
+        let msg = ParameterNumberMessage::non_registered_7_bit(ch(0), u14(3), u7(64));
+        assert!(!msg.is_registered());
+        assert!(!msg.is_14_bit());
+        let short_msgs: [Option<RawShortMessage>; 4] = msg.to_short_messages();
+        assert!(short_msgs[3].is_none());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    parameter_number_message::NULL_PARAMETER_NUMBER, ParameterNumberMessage, ShortMessage,
+    ShortMessageType, U14,
+};
+
+const CHANNEL_COUNT: usize = 16;
+
+/// Scans a stream of [`ShortMessage`]s for [`ParameterNumberMessage`]s.
+///
+/// (N)RPN messages are transmitted as a run of Control Changes on one channel: first the
+/// parameter number is selected via CC 101/100 (RPN) or CC 99/98 (NRPN), then the value follows
+/// via CC 6, optionally refined by CC 38. This scanner keeps track of the currently selected
+/// parameter number per channel and emits a message as soon as a data entry CC arrives - first a
+/// 7-bit one for CC 6 alone, then a corrected 14-bit one if CC 38 follows. Selecting the
+/// [`NULL_PARAMETER_NUMBER`] clears the current selection so that stray data entry messages
+/// aren't misattributed to whatever parameter happened to be selected last.
+///
+/// [`ShortMessage`]: trait.ShortMessage.html
+/// [`ParameterNumberMessage`]: struct.ParameterNumberMessage.html
+/// [`NULL_PARAMETER_NUMBER`]: constant.NULL_PARAMETER_NUMBER.html
+#[derive(Clone, Debug)]
+pub struct ParameterNumberMessageScanner {
+    channels: [ChannelScanState; CHANNEL_COUNT],
+}
+
+impl Default for ParameterNumberMessageScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParameterNumberMessageScanner {
+    /// Creates a new scanner.
+    pub fn new() -> ParameterNumberMessageScanner {
+        ParameterNumberMessageScanner {
+            channels: [ChannelScanState::default(); CHANNEL_COUNT],
+        }
+    }
+
+    /// Feeds the scanner a short message, returning a parameter number message if this message
+    /// completed (a 7-bit value) or refined (a 14-bit value) one.
+    pub fn feed(&mut self, msg: &impl ShortMessage) -> Option<ParameterNumberMessage> {
+        if msg.r#type() != ShortMessageType::ControlChange {
+            return None;
+        }
+        let channel = msg.channel()?;
+        let controller_number = msg.controller_number()?;
+        let value = msg.control_value()?.get();
+        let state = &mut self.channels[usize::from(channel.get())];
+        use crate::controller_numbers::*;
+        match controller_number.get() {
+            cn if cn == REGISTERED_PARAMETER_NUMBER_MSB.get() => {
+                state.number_msb = value;
+                state.is_registered = true;
+                state.selected = true;
+                state.clear_if_null();
+                None
+            }
+            cn if cn == REGISTERED_PARAMETER_NUMBER_LSB.get() => {
+                state.number_lsb = value;
+                state.is_registered = true;
+                state.selected = true;
+                state.clear_if_null();
+                None
+            }
+            cn if cn == NON_REGISTERED_PARAMETER_NUMBER_MSB.get() => {
+                state.number_msb = value;
+                state.is_registered = false;
+                state.selected = true;
+                state.clear_if_null();
+                None
+            }
+            cn if cn == NON_REGISTERED_PARAMETER_NUMBER_LSB.get() => {
+                state.number_lsb = value;
+                state.is_registered = false;
+                state.selected = true;
+                state.clear_if_null();
+                None
+            }
+            cn if cn == DATA_ENTRY_MSB.get() => {
+                if !state.selected {
+                    return None;
+                }
+                state.data_msb = value;
+                Some(ParameterNumberMessage::new_internal(
+                    channel,
+                    state.number(),
+                    U14::new(u16::from(value & 0x7f)),
+                    false,
+                    state.is_registered,
+                ))
+            }
+            cn if cn == DATA_ENTRY_LSB.get() => {
+                if !state.selected {
+                    return None;
+                }
+                state.data_lsb = value;
+                Some(ParameterNumberMessage::new_internal(
+                    channel,
+                    state.number(),
+                    combine_14_bit_value(state.data_msb, state.data_lsb),
+                    true,
+                    state.is_registered,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct ChannelScanState {
+    number_msb: u8,
+    number_lsb: u8,
+    is_registered: bool,
+    selected: bool,
+    data_msb: u8,
+    data_lsb: u8,
+}
+
+impl ChannelScanState {
+    fn number(&self) -> U14 {
+        combine_14_bit_value(self.number_msb, self.number_lsb)
+    }
+
+    fn clear_if_null(&mut self) {
+        if self.number() == NULL_PARAMETER_NUMBER {
+            self.selected = false;
+        }
+    }
+}
+
+fn combine_14_bit_value(high: u8, low: u8) -> U14 {
+    unsafe { U14::new_unchecked((u16::from(high & 0x7f) << 7) | u16::from(low & 0x7f)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{channel as ch, u14, u7};
+    use crate::{RawShortMessage, ShortMessageFactory};
+
+    fn rpn_select(channel: crate::Channel, number: U14) -> [RawShortMessage; 2] {
+        use crate::{extract_high_7_bit_value_from_14_bit_value, extract_low_7_bit_value_from_14_bit_value};
+        [
+            RawShortMessage::control_change(
+                channel,
+                crate::controller_numbers::REGISTERED_PARAMETER_NUMBER_MSB,
+                extract_high_7_bit_value_from_14_bit_value(number),
+            ),
+            RawShortMessage::control_change(
+                channel,
+                crate::controller_numbers::REGISTERED_PARAMETER_NUMBER_LSB,
+                extract_low_7_bit_value_from_14_bit_value(number),
+            ),
+        ]
+    }
+
+    #[test]
+    fn seven_bit_rpn() {
+        let mut scanner = ParameterNumberMessageScanner::new();
+        for m in rpn_select(ch(0), u14(420)) {
+            assert!(scanner.feed(&m).is_none());
+        }
+        let msg = scanner
+            .feed(&RawShortMessage::control_change(
+                ch(0),
+                crate::controller_numbers::DATA_ENTRY_MSB,
+                u7(64),
+            ))
+            .unwrap();
+        assert!(!msg.is_14_bit());
+        assert!(msg.is_registered());
+        assert_eq!(msg.number(), u14(420));
+        assert_eq!(msg.value(), u14(64));
+    }
+
+    #[test]
+    fn data_entry_without_selection_is_ignored() {
+        let mut scanner = ParameterNumberMessageScanner::new();
+        assert!(scanner
+            .feed(&RawShortMessage::control_change(
+                ch(0),
+                crate::controller_numbers::DATA_ENTRY_MSB,
+                u7(64),
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn null_rpn_clears_selection() {
+        let mut scanner = ParameterNumberMessageScanner::new();
+        for m in rpn_select(ch(0), u14(420)) {
+            scanner.feed(&m);
+        }
+        for m in rpn_select(ch(0), NULL_PARAMETER_NUMBER) {
+            scanner.feed(&m);
+        }
+        assert!(scanner
+            .feed(&RawShortMessage::control_change(
+                ch(0),
+                crate::controller_numbers::DATA_ENTRY_MSB,
+                u7(64),
+            ))
+            .is_none());
+    }
+}
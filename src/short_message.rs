@@ -0,0 +1,158 @@
+use crate::{Channel, ControllerNumber, U7};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The type of a [`ShortMessage`], as carried by its status byte.
+///
+/// [`ShortMessage`]: trait.ShortMessage.html
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ShortMessageType {
+    NoteOff,
+    NoteOn,
+    PolyphonicKeyPressure,
+    ControlChange,
+    ProgramChange,
+    ChannelPressure,
+    PitchBendChange,
+    /// Anything that isn't one of the channel voice messages above, e.g. a system message.
+    Other,
+}
+
+impl ShortMessageType {
+    fn from_status_byte(status_byte: u8) -> ShortMessageType {
+        match crate::util::extract_high_nibble_from_byte(status_byte).get() {
+            0x8 => ShortMessageType::NoteOff,
+            0x9 => ShortMessageType::NoteOn,
+            0xa => ShortMessageType::PolyphonicKeyPressure,
+            0xb => ShortMessageType::ControlChange,
+            0xc => ShortMessageType::ProgramChange,
+            0xd => ShortMessageType::ChannelPressure,
+            0xe => ShortMessageType::PitchBendChange,
+            _ => ShortMessageType::Other,
+        }
+    }
+
+    fn type_nibble(&self) -> u8 {
+        match self {
+            ShortMessageType::NoteOff => 0x8,
+            ShortMessageType::NoteOn => 0x9,
+            ShortMessageType::PolyphonicKeyPressure => 0xa,
+            ShortMessageType::ControlChange => 0xb,
+            ShortMessageType::ProgramChange => 0xc,
+            ShortMessageType::ChannelPressure => 0xd,
+            ShortMessageType::PitchBendChange => 0xe,
+            ShortMessageType::Other => 0xf,
+        }
+    }
+
+    fn is_channel_message(&self) -> bool {
+        !matches!(self, ShortMessageType::Other)
+    }
+}
+
+/// A MIDI short message, i.e. a message consisting of a status byte and up to 2 data bytes.
+///
+/// This trait only requires access to the raw bytes; all higher-level accessors (such as
+/// [`channel`] or [`controller_number`]) are derived from them.
+///
+/// [`channel`]: #method.channel
+/// [`controller_number`]: #method.controller_number
+pub trait ShortMessage {
+    /// Returns the status byte of this message.
+    fn status_byte(&self) -> u8;
+
+    /// Returns the first data byte of this message.
+    fn data_byte_1(&self) -> U7;
+
+    /// Returns the second data byte of this message.
+    fn data_byte_2(&self) -> U7;
+
+    /// Returns the type of this message.
+    fn r#type(&self) -> ShortMessageType {
+        ShortMessageType::from_status_byte(self.status_byte())
+    }
+
+    /// Returns the channel of this message, if it's a channel message.
+    fn channel(&self) -> Option<Channel> {
+        if self.r#type().is_channel_message() {
+            Some(crate::util::extract_low_nibble_from_byte(
+                self.status_byte(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the controller number of this message, if it's a Control Change message.
+    fn controller_number(&self) -> Option<ControllerNumber> {
+        if self.r#type() == ShortMessageType::ControlChange {
+            Some(unsafe { ControllerNumber::new_unchecked(self.data_byte_1().get()) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the control value of this message, if it's a Control Change message.
+    fn control_value(&self) -> Option<U7> {
+        if self.r#type() == ShortMessageType::ControlChange {
+            Some(self.data_byte_2())
+        } else {
+            None
+        }
+    }
+}
+
+/// Creates [`ShortMessage`]s of a concrete type from their semantic building blocks.
+///
+/// [`ShortMessage`]: trait.ShortMessage.html
+pub trait ShortMessageFactory: ShortMessage + Sized {
+    /// Creates a message from raw bytes.
+    fn from_bytes(status_byte: u8, data_byte_1: U7, data_byte_2: U7) -> Self;
+
+    /// Creates a Control Change message.
+    fn control_change(
+        channel: Channel,
+        controller_number: ControllerNumber,
+        control_value: U7,
+    ) -> Self {
+        let type_nibble = unsafe { Channel::new_unchecked(ShortMessageType::ControlChange.type_nibble()) };
+        let status_byte = crate::util::build_byte_from_nibbles(type_nibble, channel);
+        Self::from_bytes(status_byte, U7::new(controller_number.get()), control_value)
+    }
+}
+
+/// A plain, self-contained [`ShortMessage`] implementation that just stores its 3 raw bytes.
+///
+/// [`ShortMessage`]: trait.ShortMessage.html
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RawShortMessage {
+    status_byte: u8,
+    data_byte_1: U7,
+    data_byte_2: U7,
+}
+
+impl ShortMessage for RawShortMessage {
+    fn status_byte(&self) -> u8 {
+        self.status_byte
+    }
+
+    fn data_byte_1(&self) -> U7 {
+        self.data_byte_1
+    }
+
+    fn data_byte_2(&self) -> U7 {
+        self.data_byte_2
+    }
+}
+
+impl ShortMessageFactory for RawShortMessage {
+    fn from_bytes(status_byte: u8, data_byte_1: U7, data_byte_2: U7) -> Self {
+        RawShortMessage {
+            status_byte,
+            data_byte_1,
+            data_byte_2,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Helper functions for constructing test fixtures from raw numbers, for use in doc tests and
+//! unit tests throughout this crate (and by downstream crates writing their own tests).
+
+use crate::{Channel, ControllerNumber, KeyNumber, RawShortMessage, ShortMessageFactory, U14, U7};
+
+/// Creates a channel from a raw number, panicking if it's out of range.
+pub fn channel(number: u8) -> Channel {
+    Channel::new(number)
+}
+
+/// Creates a controller number from a raw number, panicking if it's out of range.
+pub fn controller_number(number: u8) -> ControllerNumber {
+    ControllerNumber::new(number)
+}
+
+/// Creates a key number from a raw number, panicking if it's out of range.
+pub fn key_number(number: u8) -> KeyNumber {
+    KeyNumber::new(number)
+}
+
+/// Creates a 7-bit value from a raw number, panicking if it's out of range.
+pub fn u7(value: u8) -> U7 {
+    U7::new(value)
+}
+
+/// Creates a 14-bit value from a raw number, panicking if it's out of range.
+pub fn u14(value: u16) -> U14 {
+    U14::new(value)
+}
+
+/// Creates a Control Change message from raw numbers, panicking if any of them is out of range.
+pub fn control_change(channel: u8, controller_number: u8, value: u8) -> RawShortMessage {
+    RawShortMessage::control_change(
+        self::channel(channel),
+        self::controller_number(controller_number),
+        self::u7(value),
+    )
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 14-bit value (0-16383), as assembled from a pair of 7-bit MIDI data bytes.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct U14(pub(crate) u16);
+
+impl U14 {
+    /// The smallest valid value, 0.
+    pub const MIN: U14 = U14(0);
+    /// The largest valid value, 16383.
+    pub const MAX: U14 = U14(0x3fff);
+
+    /// Creates a 14-bit value.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given number is greater than 16383.
+    pub fn new(number: u16) -> U14 {
+        assert!(number <= 0x3fff);
+        U14(number)
+    }
+
+    /// Creates a 14-bit value without checking the given number.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that the given number is less than or equal to 16383.
+    pub const unsafe fn new_unchecked(number: u16) -> U14 {
+        U14(number)
+    }
+
+    /// Returns the number of this value.
+    pub fn get(&self) -> u16 {
+        self.0
+    }
+}
+
+impl From<U14> for u16 {
+    fn from(value: U14) -> Self {
+        value.0
+    }
+}
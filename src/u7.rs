@@ -0,0 +1,50 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 7-bit value (0-127), as carried by a single MIDI data byte.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct U7(pub(crate) u8);
+
+impl U7 {
+    /// The smallest valid value, 0.
+    pub const MIN: U7 = U7(0);
+    /// The largest valid value, 127.
+    pub const MAX: U7 = U7(127);
+
+    /// Creates a 7-bit value.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given number is greater than 127.
+    pub fn new(number: u8) -> U7 {
+        assert!(number <= 127);
+        U7(number)
+    }
+
+    /// Creates a 7-bit value without checking the given number.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that the given number is less than or equal to 127.
+    pub const unsafe fn new_unchecked(number: u8) -> U7 {
+        U7(number)
+    }
+
+    /// Returns the number of this value.
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<U7> for u8 {
+    fn from(value: U7) -> Self {
+        value.0
+    }
+}
+
+impl From<U7> for u16 {
+    fn from(value: U7) -> Self {
+        u16::from(value.0)
+    }
+}
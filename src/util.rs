@@ -1,3 +1,8 @@
+//! Internal bit-twiddling helpers, safe to use from `#![no_std]` code.
+//!
+//! As in `bit_util.rs`, no change was needed here for `no_std` safety. These are the private
+//! counterparts that `bit_util.rs` delegates its public functions to.
+
 use crate::{Channel, SevenBitValue, U14};
 
 pub(crate) fn extract_high_nibble_from_byte(byte: u8) -> Channel {